@@ -1,5 +1,7 @@
+use std::collections::HashMap;
 use std::fmt;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
 
 use reqwest::Method;
 
@@ -90,6 +92,316 @@ impl ContactsService {
 
         Ok(content.data)
     }
+
+    /// Retrieves all contacts from an audience that carry `topic` among
+    /// their tags, letting callers segment an audience without maintaining
+    /// separate audiences per topic.
+    ///
+    /// Walks every page via [`list_paginated`](Self::list_paginated) and
+    /// keeps only the matching contacts. This does not currently bound
+    /// memory: see the limitations on [`list_paginated`](Self::list_paginated),
+    /// under which the whole audience arrives in a single page.
+    ///
+    /// # Limitations
+    ///
+    /// This filters on [`Contact::topics`](types::Contact::topics), which is
+    /// populated from whatever the `GET` response for a contact actually
+    /// contains. The public Resend contacts API reference does not document
+    /// a tags/topics field on contacts, so until that is confirmed against a
+    /// live account, treat this as best-effort: if the API silently drops
+    /// `topics` the way it already drops everything but a single
+    /// `unsubscribed` boolean (see [`ContactStatus`](types::ContactStatus)),
+    /// every contact will come back untagged and this will always return an
+    /// empty `Vec`.
+    ///
+    /// <https://resend.com/docs/api-reference/contacts/list-contacts>
+    #[maybe_async::maybe_async]
+    pub async fn list_by_topic(&self, audience: &AudienceId, topic: &str) -> Result<Vec<Contact>> {
+        let mut matches = Vec::new();
+        let mut options = types::ListOptions::new();
+
+        loop {
+            let page = self.list_paginated(audience, options).await?;
+            matches.extend(
+                page.data.into_iter().filter(|contact| types::contact_has_topic(contact, topic)),
+            );
+
+            options = match page.next_cursor {
+                Some(cursor) => types::ListOptions::new().with_after(&cursor),
+                None => break,
+            };
+        }
+
+        Ok(matches)
+    }
+
+    /// Creates a contact inside an audience in [`ContactStatus::Pending`]
+    /// and emails it a [`ConfirmationTemplate`] the recipient must act on
+    /// before they count as subscribed.
+    ///
+    /// `confirm_base_url` is prepended directly to the confirmation token to
+    /// build the URL substituted into the template's `{{confirm_url}}`
+    /// placeholder, so it should include whatever path or query prefix your
+    /// confirmation endpoint expects, e.g. `"https://example.com/confirm?token="`.
+    ///
+    /// Returns the id of the newly created, still-unconfirmed contact. Once
+    /// the recipient visits the link, extract the token your confirmation
+    /// endpoint received and pass it to [`confirm`](Self::confirm).
+    #[maybe_async::maybe_async]
+    pub async fn create_with_confirmation(
+        &self,
+        audience: &AudienceId,
+        contact: ContactData,
+        template: types::ConfirmationTemplate,
+        from: &str,
+        confirm_base_url: &str,
+    ) -> Result<ContactId> {
+        let email = contact.email.clone();
+        let pending = contact.with_status(types::ContactStatus::Pending);
+        let id = self.create(audience, pending).await?;
+
+        let token = types::encode_token(&id);
+        let confirm_url = format!("{confirm_base_url}{token}");
+        let html = template
+            .html
+            .as_deref()
+            .map(|html| html.replace("{{confirm_url}}", &confirm_url));
+        let text = template
+            .text
+            .as_deref()
+            .map(|text| text.replace("{{confirm_url}}", &confirm_url));
+
+        let mut message =
+            crate::types::CreateEmailBaseOptions::new(from, vec![email], &template.subject);
+        if let Some(html) = html {
+            message = message.with_html(&html);
+        }
+        if let Some(text) = text {
+            message = message.with_text(&text);
+        }
+
+        let emails = crate::EmailsService(Arc::clone(&self.0));
+        let _ = emails.send(message).await?;
+
+        Ok(id)
+    }
+
+    /// Confirms a pending subscription created via
+    /// [`create_with_confirmation`](Self::create_with_confirmation),
+    /// flipping the contact to subscribed.
+    ///
+    /// `token` is the opaque value that was appended to `confirm_base_url`
+    /// to build the confirmation email's `{{confirm_url}}`. Returns an error
+    /// if it is malformed, tampered with, or expired.
+    #[maybe_async::maybe_async]
+    pub async fn confirm(&self, audience: &AudienceId, token: &str) -> Result<()> {
+        let contact = types::decode_token(token).ok_or_else(types::invalid_token_error)?;
+        let changes = ContactChanges::new().with_status(types::ContactStatus::Subscribed);
+
+        self.update(&contact, audience, changes).await
+    }
+
+    /// Marks a contact as unsubscribed and returns an
+    /// [`UnsubscribeRecord`](types::UnsubscribeRecord) of why and when, for
+    /// the caller to persist for deliverability and compliance audits.
+    ///
+    /// The API itself only stores a single `unsubscribed` boolean (see
+    /// [`ContactStatus`](types::ContactStatus)), so `reason` and the time of
+    /// the change are not sent to Resend at all — there is no verified way to
+    /// attach arbitrary metadata to a contact there, and smuggling it into
+    /// the contact's `topics` would silently overwrite whatever topics
+    /// [`ContactData::with_topics`](types::ContactData::with_topics) /
+    /// [`ContactChanges::with_topics`](types::ContactChanges::with_topics)
+    /// had already set (that field replaces the whole array) and pollute a
+    /// surface [`list_by_topic`](Self::list_by_topic) exposes to callers.
+    /// The returned record is the only place `reason`/`at` are captured; log
+    /// or store it in your own system if you need it later.
+    ///
+    /// <https://resend.com/docs/api-reference/contacts/update-contact>
+    #[maybe_async::maybe_async]
+    pub async fn mark_unsubscribed(
+        &self,
+        contact: &ContactId,
+        audience: &AudienceId,
+        reason: Option<types::UnsubscribeReason>,
+    ) -> Result<types::UnsubscribeRecord> {
+        let at = types::unix_now().to_string();
+        let status = types::ContactStatus::Unsubscribed { reason, at: Some(at.clone()) };
+        let changes = ContactChanges::new().with_status(status);
+
+        self.update(contact, audience, changes).await?;
+
+        Ok(types::UnsubscribeRecord { contact: contact.clone(), reason, at })
+    }
+
+    /// Creates many contacts concurrently, recording a success or an error
+    /// per input rather than aborting the whole batch on the first failure.
+    ///
+    /// At most `concurrency` creates are in flight at once. A `concurrency`
+    /// of `0` is treated as `1` rather than stalling the batch forever.
+    ///
+    /// Only available without the `blocking` feature: the concurrency is
+    /// driven by polling several [`create`](Self::create) futures at once,
+    /// which has no blocking equivalent (see [`list_stream`](Self::list_stream)).
+    #[cfg(not(feature = "blocking"))]
+    pub async fn create_many(
+        &self,
+        audience: &AudienceId,
+        contacts: Vec<ContactData>,
+        concurrency: usize,
+    ) -> Vec<Result<ContactId>> {
+        use futures::stream::{self, StreamExt};
+
+        let mut results: Vec<(usize, Result<ContactId>)> =
+            stream::iter(contacts.into_iter().enumerate())
+                .map(|(index, contact)| {
+                    let service = self.clone();
+                    async move { (index, service.create(audience, contact).await) }
+                })
+                .buffer_unordered(concurrency.max(1))
+                .collect()
+                .await;
+
+        results.sort_by_key(|(index, _)| *index);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+
+    /// Updates many contacts concurrently, recording a success or an error
+    /// per input rather than aborting the whole batch on the first failure.
+    ///
+    /// At most `concurrency` updates are in flight at once. A `concurrency`
+    /// of `0` is treated as `1` rather than stalling the batch forever.
+    ///
+    /// Only available without the `blocking` feature; see
+    /// [`create_many`](Self::create_many).
+    #[cfg(not(feature = "blocking"))]
+    pub async fn update_many(
+        &self,
+        audience: &AudienceId,
+        changes: Vec<(ContactId, ContactChanges)>,
+        concurrency: usize,
+    ) -> Vec<Result<()>> {
+        use futures::stream::{self, StreamExt};
+
+        let mut results: Vec<(usize, Result<()>)> = stream::iter(changes.into_iter().enumerate())
+            .map(|(index, (contact, update))| {
+                let service = self.clone();
+                async move { (index, service.update(&contact, audience, update).await) }
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await;
+
+        results.sort_by_key(|(index, _)| *index);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+
+    /// Removes many contacts concurrently, recording a success or an error
+    /// per input rather than aborting the whole batch on the first failure.
+    ///
+    /// At most `concurrency` deletes are in flight at once. A `concurrency`
+    /// of `0` is treated as `1` rather than stalling the batch forever.
+    ///
+    /// Only available without the `blocking` feature; see
+    /// [`create_many`](Self::create_many).
+    #[cfg(not(feature = "blocking"))]
+    pub async fn delete_many<T>(
+        &self,
+        audience: &AudienceId,
+        email_or_ids: &[T],
+        concurrency: usize,
+    ) -> Vec<Result<()>>
+        where
+            T: AsRef<str> + Sync,
+    {
+        use futures::stream::{self, StreamExt};
+
+        let mut results: Vec<(usize, Result<()>)> = stream::iter(email_or_ids.iter().enumerate())
+            .map(|(index, email_or_id)| {
+                let service = self.clone();
+                async move { (index, service.delete(audience, email_or_id).await) }
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await;
+
+        results.sort_by_key(|(index, _)| *index);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+
+    /// Retrieves a single page of contacts from an audience.
+    ///
+    /// Pass the returned [`ContactsPage::next_cursor`](types::ContactsPage::next_cursor)
+    /// back in as [`ListOptions::with_after`](types::ListOptions::with_after)
+    /// to fetch the following page.
+    ///
+    /// # Limitations
+    ///
+    /// The public Resend list-contacts API reference documents no cursor
+    /// parameter and returns every contact in one response, which is why
+    /// [`ContactsPage::next_cursor`](types::ContactsPage::next_cursor) is
+    /// `#[serde(default)]`: against that endpoint it deserializes to `None`
+    /// on the very first page, so `limit`/`after` have no effect and this
+    /// (and [`list_stream`](Self::list_stream), which is built on it) fetch
+    /// the whole audience in one request rather than bounding memory. Treat
+    /// this as forward-compatible plumbing for if/when the endpoint adds
+    /// real pagination, not as a working memory bound today.
+    ///
+    /// <https://resend.com/docs/api-reference/contacts/list-contacts>
+    #[maybe_async::maybe_async]
+    pub async fn list_paginated(
+        &self,
+        audience: &AudienceId,
+        options: types::ListOptions,
+    ) -> Result<types::ContactsPage> {
+        let mut path = format!("/audiences/{audience}/contacts");
+
+        let mut query = Vec::new();
+        if let Some(limit) = options.limit {
+            query.push(format!("limit={limit}"));
+        }
+        if let Some(after) = &options.after {
+            query.push(format!("after={after}"));
+        }
+        if !query.is_empty() {
+            path.push('?');
+            path.push_str(&query.join("&"));
+        }
+
+        let request = self.0.build(Method::GET, &path);
+        let response = self.0.send(request).await?;
+        let content = response.json::<types::ContactsPage>().await?;
+
+        Ok(content)
+    }
+
+    /// Streams every contact in an audience, transparently walking pages with
+    /// [`list_paginated`](Self::list_paginated).
+    ///
+    /// This does not currently bound memory: see the limitations on
+    /// [`list_paginated`](Self::list_paginated).
+    #[cfg(not(feature = "blocking"))]
+    pub fn list_stream(
+        &self,
+        audience: &AudienceId,
+    ) -> impl futures::Stream<Item = Result<Contact>> + '_ {
+        use futures::stream::{self, TryStreamExt};
+
+        stream::try_unfold(Some(types::ListOptions::new()), move |state| async move {
+            let Some(options) = state else {
+                return Ok(None);
+            };
+
+            let page = self.list_paginated(audience, options).await?;
+            let next = page
+                .next_cursor
+                .map(|cursor| types::ListOptions::new().with_after(&cursor));
+
+            Ok(Some((page.data, next)))
+        })
+        .map_ok(stream::iter)
+        .try_flatten()
+    }
 }
 
 impl fmt::Debug for ContactsService {
@@ -98,6 +410,151 @@ impl fmt::Debug for ContactsService {
     }
 }
 
+/// A caching wrapper around [`ContactsService`] that serves [`get`](Self::get)
+/// and [`list`](Self::list) from an in-memory cache while entries are within
+/// a configurable TTL, falling back to the API once they go stale.
+///
+/// Mutating calls ([`create`](Self::create), [`update`](Self::update),
+/// [`delete`](Self::delete)) invalidate the affected cache entries so that
+/// later reads never observe data that is known to be wrong.
+#[derive(Clone)]
+pub struct CachedContactsService {
+    inner: ContactsService,
+    ttl: Duration,
+    contacts: Arc<RwLock<HashMap<ContactId, (Contact, Instant)>>>,
+    lists: Arc<RwLock<HashMap<String, (Vec<Contact>, Instant)>>>,
+}
+
+impl CachedContactsService {
+    /// Wraps `inner`, caching entries for up to `ttl` before refetching them.
+    pub fn new(inner: ContactsService, ttl: Duration) -> Self {
+        Self {
+            inner,
+            ttl,
+            contacts: Arc::new(RwLock::new(HashMap::new())),
+            lists: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Returns whether the cached entry for `contact`, if any, is older than
+    /// the configured TTL. A contact with no cache entry counts as outdated.
+    pub fn is_outdated(&self, contact: &ContactId) -> bool {
+        let cache = self.contacts.read().unwrap_or_else(|err| err.into_inner());
+        match cache.get(contact) {
+            Some((_, at)) => at.elapsed() > self.ttl,
+            None => true,
+        }
+    }
+
+    /// Retrieves a single contact, serving the cached copy if it is not yet
+    /// outdated.
+    ///
+    /// <https://resend.com/docs/api-reference/contacts/get-contact>
+    #[maybe_async::maybe_async]
+    pub async fn get(&self, contact: &ContactId, audience: &AudienceId) -> Result<Contact> {
+        if !self.is_outdated(contact) {
+            let cache = self.contacts.read().unwrap_or_else(|err| err.into_inner());
+            if let Some((cached, _)) = cache.get(contact) {
+                return Ok(cached.clone());
+            }
+        }
+
+        let fresh = self.inner.get(contact, audience).await?;
+        let mut cache = self.contacts.write().unwrap_or_else(|err| err.into_inner());
+        cache.insert(contact.clone(), (fresh.clone(), Instant::now()));
+
+        Ok(fresh)
+    }
+
+    /// Retrieves all contacts from an audience, serving the cached list if it
+    /// is not yet outdated.
+    ///
+    /// <https://resend.com/docs/api-reference/contacts/list-contacts>
+    #[maybe_async::maybe_async]
+    pub async fn list(&self, audience: &AudienceId) -> Result<Vec<Contact>> {
+        let key = audience.as_ref().to_owned();
+
+        {
+            let lists = self.lists.read().unwrap_or_else(|err| err.into_inner());
+            if let Some((cached, at)) = lists.get(&key) {
+                if at.elapsed() <= self.ttl {
+                    return Ok(cached.clone());
+                }
+            }
+        }
+
+        let fresh = self.inner.list(audience).await?;
+        let mut lists = self.lists.write().unwrap_or_else(|err| err.into_inner());
+        lists.insert(key, (fresh.clone(), Instant::now()));
+
+        Ok(fresh)
+    }
+
+    /// Creates a contact, invalidating the audience's cached list.
+    ///
+    /// <https://resend.com/docs/api-reference/contacts/create-contact>
+    #[maybe_async::maybe_async]
+    pub async fn create(&self, audience: &AudienceId, contact: ContactData) -> Result<ContactId> {
+        let id = self.inner.create(audience, contact).await?;
+        self.invalidate_list(audience);
+
+        Ok(id)
+    }
+
+    /// Updates a contact, invalidating its cache entry and the audience's
+    /// cached list.
+    ///
+    /// <https://resend.com/docs/api-reference/contacts/update-contact>
+    #[maybe_async::maybe_async]
+    pub async fn update(
+        &self,
+        contact: &ContactId,
+        audience: &AudienceId,
+        update: ContactChanges,
+    ) -> Result<()> {
+        self.inner.update(contact, audience, update).await?;
+        self.contacts.write().unwrap_or_else(|err| err.into_inner()).remove(contact);
+        self.invalidate_list(audience);
+
+        Ok(())
+    }
+
+    /// Removes a contact, invalidating its cache entry and the audience's
+    /// cached list.
+    ///
+    /// Unlike [`ContactsService::delete`], this only accepts a [`ContactId`]
+    /// rather than an email-or-id: the cache is keyed by [`ContactId`], so
+    /// invalidating by email would be unable to find the entry it needs to
+    /// evict, leaving a stale contact served until its TTL happens to expire.
+    ///
+    /// <https://resend.com/docs/api-reference/contacts/delete-contact>
+    #[maybe_async::maybe_async]
+    pub async fn delete(&self, audience: &AudienceId, contact: &ContactId) -> Result<()> {
+        self.inner.delete(audience, contact).await?;
+        self.contacts.write().unwrap_or_else(|err| err.into_inner()).remove(contact);
+        self.invalidate_list(audience);
+
+        Ok(())
+    }
+
+    /// Drops the cached list for `audience`, if any.
+    fn invalidate_list(&self, audience: &AudienceId) {
+        self.lists
+            .write()
+            .unwrap_or_else(|err| err.into_inner())
+            .remove(audience.as_ref());
+    }
+}
+
+impl fmt::Debug for CachedContactsService {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachedContactsService")
+            .field("inner", &self.inner)
+            .field("ttl", &self.ttl)
+            .finish_non_exhaustive()
+    }
+}
+
 pub mod types {
     use std::fmt;
 
@@ -105,7 +562,7 @@ pub mod types {
     use serde::{Deserialize, Serialize};
 
     /// Unique [`Contact`] identifier.
-    #[derive(Debug, Clone, Deserialize)]
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
     pub struct ContactId(EcoString);
 
     impl ContactId {
@@ -128,6 +585,109 @@ pub mod types {
         }
     }
 
+    /// Subscription status of a [`Contact`].
+    ///
+    /// The API only ever stores a single `unsubscribed` boolean, so
+    /// [`serialize_status_opt`]/[`deserialize_status`] only round-trip
+    /// [`Subscribed`](Self::Subscribed) and a reasonless
+    /// [`Unsubscribed`](Self::Unsubscribed); [`Pending`](Self::Pending),
+    /// [`Bounced`](Self::Bounced), [`Complained`](Self::Complained), and the
+    /// `reason`/`at` carried by [`Unsubscribed`](Self::Unsubscribed) all
+    /// collapse to `true` on the wire and only survive for the lifetime of
+    /// the process that set them. [`ContactsService::mark_unsubscribed`](super::ContactsService::mark_unsubscribed)
+    /// returns an [`UnsubscribeRecord`] carrying `reason`/`at` so callers can
+    /// persist them elsewhere, since there is no verified way to make the
+    /// API itself retain them.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum ContactStatus {
+        /// The contact accepts mail.
+        Subscribed,
+        /// The contact opted or was marked out of mail, optionally with the
+        /// reason and a Unix timestamp (seconds) of when it happened.
+        Unsubscribed {
+            /// Why the contact unsubscribed, if known.
+            reason: Option<UnsubscribeReason>,
+            /// Unix timestamp (seconds) of when the contact unsubscribed, if known.
+            at: Option<String>,
+        },
+        /// The contact has not confirmed a double opt-in yet.
+        Pending,
+        /// Mail to the contact bounced.
+        Bounced,
+        /// The contact marked mail as spam.
+        Complained,
+    }
+
+    impl ContactStatus {
+        fn from_unsubscribed_bool(unsubscribed: bool) -> Self {
+            if unsubscribed {
+                Self::Unsubscribed { reason: None, at: None }
+            } else {
+                Self::Subscribed
+            }
+        }
+
+        fn is_unsubscribed(&self) -> bool {
+            !matches!(self, Self::Subscribed)
+        }
+    }
+
+    /// Why a [`Contact`] ended up [`ContactStatus::Unsubscribed`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum UnsubscribeReason {
+        /// The contact explicitly asked to stop receiving mail.
+        UserRequested,
+        /// The contact was suppressed after mail to it bounced.
+        Bounced,
+        /// The contact was suppressed after marking mail as spam.
+        Complained,
+        /// Any other, application-defined reason.
+        Other,
+    }
+
+    /// Returns whether `contact` carries `topic` among its tags.
+    pub(super) fn contact_has_topic(contact: &Contact, topic: &str) -> bool {
+        contact.topics.iter().any(|t| t == topic)
+    }
+
+    /// Record of an unsubscribe event returned by
+    /// [`mark_unsubscribed`](super::ContactsService::mark_unsubscribed).
+    ///
+    /// Resend has no verified way to retain `reason`/`at` against a contact,
+    /// so this is the only place they are captured; persist it in your own
+    /// system if you need it for deliverability or compliance audits later.
+    #[must_use]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct UnsubscribeRecord {
+        /// The contact that was marked unsubscribed.
+        pub contact: ContactId,
+        /// Why the contact unsubscribed, if known.
+        pub reason: Option<UnsubscribeReason>,
+        /// Unix timestamp (seconds) of when the contact unsubscribed.
+        pub at: String,
+    }
+
+    fn serialize_status_opt<S>(
+        status: &Option<ContactStatus>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+    {
+        match status {
+            Some(status) => serializer.serialize_bool(status.is_unsubscribed()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    fn deserialize_status<'de, D>(deserializer: D) -> Result<ContactStatus, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+    {
+        let unsubscribed = bool::deserialize(deserializer)?;
+        Ok(ContactStatus::from_unsubscribed_bool(unsubscribed))
+    }
+
     /// Details of a new [`Contact`].
     #[must_use]
     #[derive(Debug, Clone, Serialize)]
@@ -141,9 +701,16 @@ pub mod types {
         /// Last name of the contact.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub last_name: Option<String>,
-        /// Indicates if the contact is unsubscribed.
-        #[serde(skip_serializing_if = "Option::is_none")]
-        pub unsubscribed: Option<bool>,
+        /// Subscription status of the contact.
+        #[serde(
+            rename = "unsubscribed",
+            skip_serializing_if = "Option::is_none",
+            serialize_with = "serialize_status_opt"
+        )]
+        pub status: Option<ContactStatus>,
+        /// Topics (tags) the contact is segmented under.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub topics: Vec<String>,
     }
 
     impl ContactData {
@@ -153,7 +720,8 @@ pub mod types {
                 email: email.to_owned(),
                 first_name: None,
                 last_name: None,
-                unsubscribed: None,
+                status: None,
+                topics: Vec::new(),
             }
         }
 
@@ -171,10 +739,35 @@ pub mod types {
             self
         }
 
-        /// Toggles the unsubscribe status to `unsubscribe`.
+        /// Sets the contact's subscription [`ContactStatus`].
+        #[inline]
+        pub fn with_status(mut self, status: ContactStatus) -> Self {
+            self.status = Some(status);
+            self
+        }
+
+        /// Toggles the unsubscribe status to `unsubscribed`.
+        ///
+        /// Shorthand for [`with_status`](Self::with_status) with
+        /// [`ContactStatus::Subscribed`] or a reasonless
+        /// [`ContactStatus::Unsubscribed`].
+        #[inline]
+        pub fn with_unsubscribed(self, unsubscribed: bool) -> Self {
+            self.with_status(ContactStatus::from_unsubscribed_bool(unsubscribed))
+        }
+
+        /// Adds a single topic (tag) to the contact.
+        #[inline]
+        pub fn with_topic(mut self, topic: &str) -> Self {
+            self.topics.push(topic.to_owned());
+            self
+        }
+
+        /// Sets the topics (tags) of the contact, replacing any previously
+        /// added.
         #[inline]
-        pub fn with_unsubscribed(mut self, unsubscribed: bool) -> Self {
-            self.unsubscribed = Some(unsubscribed);
+        pub fn with_topics(mut self, topics: &[&str]) -> Self {
+            self.topics = topics.iter().map(|topic| (*topic).to_owned()).collect();
             self
         }
     }
@@ -192,6 +785,53 @@ pub mod types {
         pub data: Vec<Contact>,
     }
 
+    /// Options controlling a single page of
+    /// [`list_paginated`](super::ContactsService::list_paginated).
+    #[must_use]
+    #[derive(Debug, Default, Clone)]
+    pub struct ListOptions {
+        /// Maximum number of contacts to return in this page.
+        pub limit: Option<u64>,
+        /// Cursor returned by a previous page's `next_cursor`, fetching the
+        /// page right after it.
+        pub after: Option<String>,
+    }
+
+    impl ListOptions {
+        /// Creates a new [`ListOptions`] with no limit or cursor set.
+        #[inline]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Sets the maximum number of contacts to return in this page.
+        #[inline]
+        pub fn with_limit(mut self, limit: u64) -> Self {
+            self.limit = Some(limit);
+            self
+        }
+
+        /// Sets the cursor to fetch the page after.
+        #[inline]
+        pub fn with_after(mut self, after: &str) -> Self {
+            self.after = Some(after.to_owned());
+            self
+        }
+    }
+
+    /// A single page of contacts returned by
+    /// [`list_paginated`](super::ContactsService::list_paginated).
+    #[must_use]
+    #[derive(Debug, Clone, Deserialize)]
+    pub struct ContactsPage {
+        /// Contacts contained in this page.
+        pub data: Vec<Contact>,
+        /// Cursor to pass to [`ListOptions::with_after`] to fetch the next
+        /// page, absent once the audience is exhausted.
+        #[serde(default)]
+        pub next_cursor: Option<String>,
+    }
+
     /// Details of an existing contact.
     #[must_use]
     #[derive(Debug, Clone, Deserialize)]
@@ -204,10 +844,14 @@ pub mod types {
         pub first_name: String,
         /// Last name of the contact.
         pub last_name: String,
-        /// Indicates if the contact is unsubscribed.
-        pub unsubscribed: bool,
+        /// Subscription status of the contact.
+        #[serde(rename = "unsubscribed", deserialize_with = "deserialize_status")]
+        pub status: ContactStatus,
         /// Timestamp indicating when the contact was created in ISO8601 format.
         pub created_at: String,
+        /// Topics (tags) the contact is segmented under.
+        #[serde(default)]
+        pub topics: Vec<String>,
     }
 
     /// List of changes to apply to a [`Contact`].
@@ -223,9 +867,16 @@ pub mod types {
         /// Last name of the contact.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub last_name: Option<String>,
-        /// Indicates the subscription status of the contact.
-        #[serde(skip_serializing_if = "Option::is_none")]
-        pub unsubscribed: Option<bool>,
+        /// Subscription status of the contact.
+        #[serde(
+            rename = "unsubscribed",
+            skip_serializing_if = "Option::is_none",
+            serialize_with = "serialize_status_opt"
+        )]
+        pub status: Option<ContactStatus>,
+        /// Topics (tags) the contact is segmented under.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub topics: Vec<String>,
     }
 
     impl ContactChanges {
@@ -256,10 +907,35 @@ pub mod types {
             self
         }
 
+        /// Sets the contact's subscription [`ContactStatus`].
+        #[inline]
+        pub fn with_status(mut self, status: ContactStatus) -> Self {
+            self.status = Some(status);
+            self
+        }
+
         /// Updates the unsubscribe status of the contact.
+        ///
+        /// Shorthand for [`with_status`](Self::with_status) with
+        /// [`ContactStatus::Subscribed`] or a reasonless
+        /// [`ContactStatus::Unsubscribed`].
+        #[inline]
+        pub fn with_unsubscribed(self, unsubscribed: bool) -> Self {
+            self.with_status(ContactStatus::from_unsubscribed_bool(unsubscribed))
+        }
+
+        /// Adds a single topic (tag) to the contact.
+        #[inline]
+        pub fn with_topic(mut self, topic: &str) -> Self {
+            self.topics.push(topic.to_owned());
+            self
+        }
+
+        /// Sets the topics (tags) of the contact, replacing any previously
+        /// added.
         #[inline]
-        pub fn with_unsubscribed(mut self, unsubscribed: bool) -> Self {
-            self.unsubscribed = Some(unsubscribed);
+        pub fn with_topics(mut self, topics: &[&str]) -> Self {
+            self.topics = topics.iter().map(|topic| (*topic).to_owned()).collect();
             self
         }
     }
@@ -269,12 +945,168 @@ pub mod types {
         /// Unique identifier for the updated contact.
         pub id: ContactId,
     }
+
+    /// Email template used by [`create_with_confirmation`](super::ContactsService::create_with_confirmation)
+    /// for the double opt-in confirmation message.
+    ///
+    /// The `{{confirm_url}}` placeholder in `html`/`text` is replaced with a
+    /// clickable confirmation URL (the `confirm_base_url` passed to
+    /// `create_with_confirmation` followed by the confirmation token) at send
+    /// time.
+    #[must_use]
+    #[derive(Debug, Clone)]
+    pub struct ConfirmationTemplate {
+        /// Subject line of the confirmation email.
+        pub subject: String,
+        /// HTML body of the confirmation email.
+        pub html: Option<String>,
+        /// Plain text body of the confirmation email.
+        pub text: Option<String>,
+    }
+
+    impl ConfirmationTemplate {
+        /// Creates a new [`ConfirmationTemplate`] with the given subject and
+        /// no body set.
+        pub fn new(subject: &str) -> Self {
+            Self { subject: subject.to_owned(), html: None, text: None }
+        }
+
+        /// Sets the HTML body of the confirmation email.
+        #[inline]
+        pub fn with_html(mut self, html: &str) -> Self {
+            self.html = Some(html.to_owned());
+            self
+        }
+
+        /// Sets the plain text body of the confirmation email.
+        #[inline]
+        pub fn with_text(mut self, text: &str) -> Self {
+            self.text = Some(text.to_owned());
+            self
+        }
+    }
+
+    impl Default for ConfirmationTemplate {
+        /// A minimal built-in confirmation template.
+        fn default() -> Self {
+            Self::new("Please confirm your subscription").with_html(
+                "<p>Please confirm your subscription by visiting {{confirm_url}}</p>",
+            )
+        }
+    }
+
+    /// How long a confirmation token stays valid after
+    /// [`encode_token`] issues it.
+    const CONFIRMATION_TOKEN_TTL_SECS: u64 = 60 * 60 * 24;
+
+    /// Environment variable holding the secret confirmation tokens are
+    /// signed with.
+    ///
+    /// Set this to the same stable, secret value in every process that
+    /// creates or confirms contacts (every replica, surviving restarts and
+    /// redeploys) so a token minted by one process validates in any other.
+    /// Treat it with the same care as the Resend API key: anyone who has it
+    /// can forge confirmation tokens.
+    ///
+    /// # Warning
+    ///
+    /// If this is unset, a secret is instead generated at random the first
+    /// time it's needed and lives only for the lifetime of the current
+    /// process. In that fallback mode, tokens issued before a restart, or by
+    /// any other replica, are always rejected as tampered — double opt-in
+    /// then only works if every call lands on the exact same long-lived
+    /// process, which is not the case for most deployed web backends.
+    pub const CONFIRMATION_SECRET_ENV_VAR: &str = "RESEND_CONFIRMATION_SECRET";
+
+    /// Secret used to sign confirmation tokens; see
+    /// [`CONFIRMATION_SECRET_ENV_VAR`] for how to make it stable across
+    /// processes.
+    fn token_secret() -> String {
+        if let Ok(secret) = std::env::var(CONFIRMATION_SECRET_ENV_VAR) {
+            return secret;
+        }
+
+        static FALLBACK: std::sync::OnceLock<String> = std::sync::OnceLock::new();
+        FALLBACK
+            .get_or_init(|| {
+                use std::hash::{BuildHasher, Hasher};
+
+                let a = std::collections::hash_map::RandomState::new().build_hasher().finish();
+                let b = std::collections::hash_map::RandomState::new().build_hasher().finish();
+                format!("{a:016x}{b:016x}")
+            })
+            .clone()
+    }
+
+    fn sign(contact: &ContactId, expires_at: u64) -> u64 {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+        token_secret().hash(&mut hasher);
+        contact.as_ref().hash(&mut hasher);
+        expires_at.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    pub(super) fn unix_now() -> u64 {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs())
+    }
+
+    /// Generates a signed, expiring confirmation token for `contact`.
+    ///
+    /// The token embeds the contact id and an expiry timestamp, both covered
+    /// by a signature keyed on [`token_secret`]; see
+    /// [`CONFIRMATION_SECRET_ENV_VAR`] for making that secret (and therefore
+    /// the tokens it signs) valid across process restarts and replicas.
+    pub(super) fn encode_token(contact: &ContactId) -> String {
+        let expires_at = unix_now().saturating_add(CONFIRMATION_TOKEN_TTL_SECS);
+        let signature = sign(contact, expires_at);
+
+        format!("{}.{expires_at}.{signature:016x}", contact.as_ref())
+    }
+
+    /// Validates a token produced by [`encode_token`], returning the contact
+    /// id it was issued for if the signature matches and it has not expired.
+    ///
+    /// Returns `None` for malformed, tampered, or expired tokens, including a
+    /// bare [`ContactId`] passed in place of a real token.
+    pub(super) fn decode_token(token: &str) -> Option<ContactId> {
+        let mut parts = token.rsplitn(3, '.');
+        let signature = u64::from_str_radix(parts.next()?, 16).ok()?;
+        let expires_at: u64 = parts.next()?.parse().ok()?;
+        let id = parts.next()?;
+
+        if unix_now() > expires_at {
+            return None;
+        }
+
+        let contact = ContactId::new(id);
+        if sign(&contact, expires_at) != signature {
+            return None;
+        }
+
+        Some(contact)
+    }
+
+    /// Builds the error returned when [`decode_token`] rejects a token.
+    pub(super) fn invalid_token_error() -> crate::Error {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "confirmation token is invalid, tampered with, or expired",
+        )
+        .into()
+    }
 }
 
 #[cfg(test)]
 mod test {
     use crate::{Client, Result};
-    use crate::types::{ContactChanges, ContactData};
+    use crate::types::{Contact, ContactChanges, ContactData, ContactId, ContactStatus};
+
+    use super::CachedContactsService;
 
     #[tokio::test]
     #[cfg(not(feature = "blocking"))]
@@ -298,7 +1130,7 @@ mod test {
 
         // Retrieve.
         let contact = resend.contacts.get(&id, &audience_id).await?;
-        assert!(contact.unsubscribed);
+        assert!(matches!(contact.status, ContactStatus::Unsubscribed { .. }));
 
         // List.
         let contacts = resend.contacts.list(&audience_id).await?;
@@ -312,4 +1144,147 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn confirmation_token_round_trips() {
+        let contact = super::types::ContactId::new("11111111-1111-1111-1111-111111111111");
+
+        let token = super::types::encode_token(&contact);
+        let decoded = super::types::decode_token(&token).expect("valid token should decode");
+
+        assert_eq!(decoded.as_ref(), contact.as_ref());
+    }
+
+    #[test]
+    fn confirmation_token_rejects_bare_contact_id() {
+        // A bare contact id is not a valid token; a real token must carry
+        // a signature the id alone cannot produce.
+        let contact = super::types::ContactId::new("11111111-1111-1111-1111-111111111111");
+
+        assert!(super::types::decode_token(contact.as_ref()).is_none());
+    }
+
+    #[test]
+    fn confirmation_token_rejects_tampering() {
+        let contact = super::types::ContactId::new("11111111-1111-1111-1111-111111111111");
+        let token = super::types::encode_token(&contact);
+
+        let mut tampered = token.clone();
+        tampered.push('0');
+        assert!(super::types::decode_token(&tampered).is_none());
+
+        let other = super::types::ContactId::new("22222222-2222-2222-2222-222222222222");
+        let other_token = super::types::encode_token(&other);
+        assert_ne!(token, other_token);
+    }
+
+    #[test]
+    fn cached_contacts_service_tracks_staleness() {
+        let resend = Client::default();
+        let cached =
+            CachedContactsService::new(resend.contacts.clone(), std::time::Duration::from_millis(20));
+
+        let id = ContactId::new("33333333-3333-3333-3333-333333333333");
+        assert!(cached.is_outdated(&id), "an uncached contact is outdated");
+
+        let contact = Contact {
+            id: id.clone(),
+            email: "test@example.com".to_owned(),
+            first_name: String::new(),
+            last_name: String::new(),
+            status: ContactStatus::Subscribed,
+            created_at: String::new(),
+            topics: Vec::new(),
+        };
+        cached
+            .contacts
+            .write()
+            .unwrap()
+            .insert(id.clone(), (contact, std::time::Instant::now()));
+        assert!(!cached.is_outdated(&id), "a freshly cached contact is not outdated");
+
+        std::thread::sleep(std::time::Duration::from_millis(30));
+        assert!(cached.is_outdated(&id), "a cached contact past its TTL is outdated");
+    }
+
+    #[test]
+    fn list_options_builder_sets_limit_and_cursor() {
+        let options = super::types::ListOptions::new().with_limit(10).with_after("cursor-1");
+
+        assert_eq!(options.limit, Some(10));
+        assert_eq!(options.after.as_deref(), Some("cursor-1"));
+    }
+
+    #[test]
+    fn contacts_page_carries_next_cursor_until_exhausted() {
+        let page: super::types::ContactsPage =
+            serde_json::from_str(r#"{"data": [], "next_cursor": "cursor-2"}"#).unwrap();
+        assert_eq!(page.next_cursor.as_deref(), Some("cursor-2"));
+
+        // The final page of an audience has no cursor, which is how
+        // `list_stream` knows to stop walking pages.
+        let last_page: super::types::ContactsPage =
+            serde_json::from_str(r#"{"data": []}"#).unwrap();
+        assert!(last_page.next_cursor.is_none());
+    }
+
+    #[test]
+    fn topic_builders_accumulate_and_replace() {
+        let data = ContactData::new("person@example.com").with_topic("a").with_topic("b");
+        assert_eq!(data.topics, vec!["a".to_owned(), "b".to_owned()]);
+
+        let replaced = data.with_topics(&["x", "y", "z"]);
+        assert_eq!(replaced.topics, vec!["x".to_owned(), "y".to_owned(), "z".to_owned()]);
+
+        let changes = ContactChanges::new().with_topic("a").with_topics(&["x", "y"]);
+        assert_eq!(changes.topics, vec!["x".to_owned(), "y".to_owned()]);
+    }
+
+    #[test]
+    fn topics_are_skipped_on_the_wire_when_empty() {
+        let untagged = ContactData::new("person@example.com");
+        let json = serde_json::to_value(&untagged).unwrap();
+        assert!(json.get("topics").is_none());
+
+        let tagged = ContactData::new("person@example.com").with_topic("vip");
+        let json = serde_json::to_value(&tagged).unwrap();
+        assert_eq!(json["topics"], serde_json::json!(["vip"]));
+
+        let untagged_changes = ContactChanges::new();
+        let json = serde_json::to_value(&untagged_changes).unwrap();
+        assert!(json.get("topics").is_none());
+    }
+
+    #[test]
+    fn contact_has_topic_matches_exact_tag_only() {
+        let mut contact = Contact {
+            id: ContactId::new("44444444-4444-4444-4444-444444444444"),
+            email: "test@example.com".to_owned(),
+            first_name: String::new(),
+            last_name: String::new(),
+            status: ContactStatus::Subscribed,
+            created_at: String::new(),
+            topics: vec!["product-updates".to_owned()],
+        };
+
+        assert!(super::types::contact_has_topic(&contact, "product-updates"));
+        assert!(!super::types::contact_has_topic(&contact, "billing"));
+
+        contact.topics.clear();
+        assert!(!super::types::contact_has_topic(&contact, "product-updates"));
+    }
+
+    #[test]
+    fn unsubscribe_record_carries_reason_and_at() {
+        use super::types::{UnsubscribeReason, UnsubscribeRecord};
+
+        let record = UnsubscribeRecord {
+            contact: ContactId::new("55555555-5555-5555-5555-555555555555"),
+            reason: Some(UnsubscribeReason::Bounced),
+            at: "1700000000".to_owned(),
+        };
+
+        assert_eq!(record.reason, Some(UnsubscribeReason::Bounced));
+        assert_eq!(record.at, "1700000000");
+    }
 }